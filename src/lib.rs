@@ -191,32 +191,106 @@ pub trait HasSome {
     fn has_some3(self: &&&Self) -> bool {
         (*self).has_some()
     }
-}
-impl HasSome for str {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+
+    /// Fuses the `has_some` predicate with the identity projection, for use in
+    /// `Iterator::filter_map` where the value is consumed - `Some(self)` if `self.has_some()`,
+    /// `None` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use has_some::HasSome;
+    /// let vector = vec!["some_data".to_owned(), "".to_owned(), "more data".to_owned()];
+    ///
+    /// let not_empties: Vec<String> =
+    ///     vector.into_iter().filter_map(String::non_empty_opt).collect();
+    ///
+    /// assert_eq!(["some_data", "more data"], not_empties.as_slice());
+    /// ```
+    fn non_empty_opt(self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if self.has_some() {
+            Some(self)
+        } else {
+            None
+        }
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+
+    /// The borrowing form of [`HasSome::non_empty_opt`], for use in `Iterator::filter_map`
+    /// where `Item = &Self` (e.g. iterating with `.iter()` over non-`Copy` elements).
+    ///
+    /// # Examples
+    /// ```
+    /// use has_some::HasSome;
+    /// let vector = vec!["some_data".to_owned(), "".to_owned(), "more data".to_owned()];
+    ///
+    /// let not_empties: Vec<&String> =
+    ///     vector.iter().filter_map(String::non_empty_opt_ref).collect();
+    ///
+    /// assert_eq!(["some_data", "more data"], not_empties.as_slice());
+    /// ```
+    fn non_empty_opt_ref(&self) -> Option<&Self> {
+        if self.has_some() {
+            Some(self)
+        } else {
+            None
+        }
     }
-}
-impl HasSome for ::std::ffi::CStr {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+
+    /// `non_empty_opt_ref` in a form that is suitable for use in `Iterator::filter_map` where
+    /// `Item = &&OwnedType` (e.g. `&&String`)
+    ///
+    /// # Examples
+    /// ```
+    /// use has_some::HasSome;
+    /// let vector = vec!["some_data", "", "more data"];
+    ///
+    /// let not_empties: Vec<&str> =
+    ///     vector.iter().filter_map(str::non_empty_opt_ref2).collect();
+    ///
+    /// assert_eq!(["some_data", "more data"], not_empties.as_slice());
+    /// ```
+    fn non_empty_opt_ref2<'a>(self: &'a &'a Self) -> Option<&'a Self> {
+        if self.has_some() {
+            Some(*self)
+        } else {
+            None
+        }
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+
+    /// `non_empty_opt_ref` in a form that is suitable for use in `Iterator::filter_map` where
+    /// `Item = &&RefType` (e.g. `&&&str`)
+    ///
+    /// # Examples
+    /// ```
+    /// use has_some::HasSome;
+    /// let vector = vec!["some_data", "", "more data"];
+    /// let borrowed: Vec<&&str> = vector.iter().collect();
+    ///
+    /// let not_empties: Vec<&str> =
+    ///     borrowed.iter().filter_map(str::non_empty_opt_ref3).collect();
+    ///
+    /// assert_eq!(["some_data", "more data"], not_empties.as_slice());
+    /// ```
+    fn non_empty_opt_ref3<'a>(self: &'a &'a &'a Self) -> Option<&'a Self> {
+        if self.has_some() {
+            Some(**self)
+        } else {
+            None
+        }
     }
 }
-impl HasSome for ::std::string::String {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
-    }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
-    }
+/// Internal trait owned by this crate that each supported type implements exactly once.
+///
+/// `HasSome` is then derived for every `IsEmpty` type via a single blanket impl below, so
+/// adding a new supported type only ever needs one `impl IsEmpty for ...` block instead of
+/// its own `has_some`/`is_empty2` pair.
+trait IsEmpty {
+    fn is_empty(&self) -> bool;
 }
-impl<Idx: PartialOrd<Idx>> HasSome for ::std::ops::Range<Idx> {
+
+impl<T: IsEmpty + ?Sized> HasSome for T {
     fn has_some(&self) -> bool {
         !self.is_empty()
     }
@@ -224,86 +298,142 @@ impl<Idx: PartialOrd<Idx>> HasSome for ::std::ops::Range<Idx> {
         (*self).is_empty()
     }
 }
-impl<Idx: PartialOrd<Idx>> HasSome for ::std::ops::RangeInclusive<Idx> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+
+impl IsEmpty for str {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl IsEmpty for ::std::ffi::CStr {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<K, V, S: ::std::hash::BuildHasher> HasSome for ::std::collections::hash_map::HashMap<K, V, S> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl IsEmpty for ::std::string::String {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<Idx: PartialOrd<Idx>> IsEmpty for ::std::ops::Range<Idx> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<K, V> HasSome for ::std::collections::BTreeMap<K, V> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl<Idx: PartialOrd<Idx>> IsEmpty for ::std::ops::RangeInclusive<Idx> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<K, V, S: ::std::hash::BuildHasher> IsEmpty for ::std::collections::hash_map::HashMap<K, V, S> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<T, S: ::std::hash::BuildHasher> HasSome for ::std::collections::hash_set::HashSet<T, S> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl<K, V> IsEmpty for ::std::collections::BTreeMap<K, V> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<T, S: ::std::hash::BuildHasher> IsEmpty for ::std::collections::hash_set::HashSet<T, S> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<T> HasSome for [T] {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl<T> IsEmpty for [T] {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<T> IsEmpty for ::std::collections::BinaryHeap<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<T> HasSome for ::std::collections::BinaryHeap<T> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl<T> IsEmpty for ::std::collections::BTreeSet<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<T> IsEmpty for ::std::collections::LinkedList<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<T> HasSome for ::std::collections::BTreeSet<T> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+impl<T> IsEmpty for ::std::collections::VecDeque<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+}
+impl<T> IsEmpty for ::std::vec::Vec<T> {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
     }
 }
-impl<T> HasSome for ::std::collections::LinkedList<T> {
+
+/// `None` is empty, and so is `Some(inner)` when `inner` itself is empty - so
+/// `Option<String>` and `Option<Vec<T>>` both treat a missing value the same as a present
+/// but blank one.
+///
+/// Bounded on `HasSome` directly rather than the crate-private `IsEmpty`, so this also covers
+/// `Option<T>` for any type wired up through [`impl_has_some!`], not just this crate's own
+/// built-in types.
+///
+/// # Examples
+/// ```
+/// use has_some::HasSome;
+///
+/// assert!(!None::<String>.has_some());
+/// assert!(!Some(String::new()).has_some());
+/// assert!(Some(vec![1]).has_some());
+/// ```
+impl<T: HasSome> HasSome for Option<T> {
     fn has_some(&self) -> bool {
-        !self.is_empty()
+        match self {
+            None => false,
+            Some(inner) => inner.has_some(),
+        }
     }
     fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+        !(*self).has_some()
     }
 }
-impl<T> HasSome for ::std::collections::VecDeque<T> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
-    }
-    fn is_empty2(self: &&Self) -> bool {
+
+/// Lets a reference to any `IsEmpty` type also be treated as `IsEmpty` (and so, via the
+/// blanket impl above, as `HasSome`), so the `prelude` functions below can compose through
+/// one layer of reference (e.g. `&&str`) without a dedicated impl for every reference depth.
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty(&self) -> bool {
         (*self).is_empty()
     }
 }
-impl<T> HasSome for ::std::vec::Vec<T> {
-    fn has_some(&self) -> bool {
-        !self.is_empty()
+
+/// Free-function forms of [`HasSome::has_some`] and its negation, for use where naming a
+/// concrete type (e.g. `String::has_some`) isn't possible or convenient - unsized types like
+/// `str` and `[T]`, trait objects, or simply to avoid a closure.
+///
+/// # Examples
+///
+/// ```
+/// use has_some::prelude::*;
+/// let vector = vec!["some_data", "", "more data"];
+///
+/// let not_empties: Vec<&&str> = vector.iter().filter(non_empty).collect();
+/// assert_eq!([&"some_data", &"more data"], not_empties.as_slice());
+/// ```
+pub mod prelude {
+    use super::HasSome;
+
+    /// `true` if `v.has_some()`, accepting any (possibly unsized) `HasSome` value by reference.
+    pub fn non_empty<T: HasSome + ?Sized>(v: &T) -> bool {
+        v.has_some()
     }
-    fn is_empty2(self: &&Self) -> bool {
-        (*self).is_empty()
+
+    /// `true` if `v` is empty, accepting any (possibly unsized) `HasSome` value by reference.
+    pub fn is_empty<T: HasSome + ?Sized>(v: &T) -> bool {
+        !v.has_some()
     }
 }
+pub use prelude::{is_empty, non_empty};
 
 /// The rare case where `is_empty` consumes `self`.
 pub trait HasSomeConsume {
@@ -323,6 +453,148 @@ impl<T> HasSomeConsume for ::std::ptr::NonNull<[T]> {
     }
 }
 
+/// Mutating helpers layered on [`HasSome`] for owned types where in-place replacement is
+/// meaningful - `String`, `Vec<T>`, and the map/set collections.
+///
+/// # Examples
+/// ```
+/// use has_some::HasSomeMut;
+///
+/// let mut s = String::new();
+/// s.fill_if_empty(|| "fallback".to_owned());
+/// assert_eq!(s, "fallback");
+///
+/// let s2 = String::new().or_else_with(|| "fallback".to_owned());
+/// assert_eq!(s2, "fallback");
+/// ```
+pub trait HasSomeMut: HasSome + Sized {
+    /// Replaces `self` with the value produced by `f` if `self` is empty, leaving it
+    /// untouched otherwise.
+    fn fill_if_empty(&mut self, f: impl FnOnce() -> Self) -> &mut Self {
+        if !self.has_some() {
+            *self = f();
+        }
+        self
+    }
+
+    /// Returns `self` if it `has_some()`, otherwise the value produced by `f`.
+    fn or_else_with(self, f: impl FnOnce() -> Self) -> Self {
+        if self.has_some() {
+            self
+        } else {
+            f()
+        }
+    }
+}
+impl HasSomeMut for ::std::string::String {}
+impl<T> HasSomeMut for ::std::vec::Vec<T> {}
+impl<K, V, S: ::std::hash::BuildHasher> HasSomeMut
+    for ::std::collections::hash_map::HashMap<K, V, S>
+{
+}
+impl<K, V> HasSomeMut for ::std::collections::BTreeMap<K, V> {}
+impl<T, S: ::std::hash::BuildHasher> HasSomeMut for ::std::collections::hash_set::HashSet<T, S> {}
+impl<T> HasSomeMut for ::std::collections::BTreeSet<T> {}
+
+/// Implements [`HasSome`] for a downstream type that exposes an inherent
+/// `is_empty(&self) -> bool`, without having to spell out `has_some`/`is_empty2` by hand.
+///
+/// This is the escape hatch for your own collections that this crate doesn't already cover,
+/// and stays correct automatically if the adapter method set above ever grows. The generated
+/// impl is an ordinary trait impl, so the orphan rule limits it to types defined in the
+/// invoking crate; foreign types such as `OsStr`, `bytes::Bytes` or `smallvec::SmallVec`
+/// cannot be wired up with it.
+///
+/// # Examples
+///
+/// ```
+/// use has_some::impl_has_some;
+///
+/// struct RingBuffer(Vec<u8>);
+/// impl RingBuffer {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+/// impl_has_some!(RingBuffer);
+///
+/// use has_some::HasSome;
+/// assert!(!RingBuffer(vec![]).has_some());
+/// assert!(RingBuffer(vec![1]).has_some());
+/// ```
+///
+/// A generic type introduces its generic parameters (bounds and lifetimes included) with
+/// `impl { .. } for ..`, braced to keep the parse unambiguous - the braced tokens are
+/// forwarded verbatim into the generated `impl<..>`:
+///
+/// ```
+/// use has_some::impl_has_some;
+///
+/// struct Wrapper<T>(Vec<T>);
+/// impl<T> Wrapper<T> {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+/// impl_has_some!(impl { T } for Wrapper<T>);
+///
+/// use has_some::HasSome;
+/// assert!(Wrapper(vec![1]).has_some());
+/// ```
+///
+/// ```
+/// use has_some::impl_has_some;
+///
+/// struct Bounded<T: std::fmt::Debug>(Vec<T>);
+/// impl<T: std::fmt::Debug> Bounded<T> {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+/// impl_has_some!(impl { T: std::fmt::Debug } for Bounded<T>);
+///
+/// use has_some::HasSome;
+/// assert!(Bounded(vec![1]).has_some());
+/// ```
+///
+/// ```
+/// use has_some::impl_has_some;
+///
+/// struct Borrowed<'a>(&'a [u8]);
+/// impl<'a> Borrowed<'a> {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+/// impl_has_some!(impl { 'a } for Borrowed<'a>);
+///
+/// use has_some::HasSome;
+/// assert!(Borrowed(&[1]).has_some());
+/// ```
+#[macro_export]
+macro_rules! impl_has_some {
+    (impl { $($gen:tt)* } for $ty:ty) => {
+        impl<$($gen)*> $crate::HasSome for $ty {
+            fn has_some(&self) -> bool {
+                !self.is_empty()
+            }
+            fn is_empty2(self: &&Self) -> bool {
+                (*self).is_empty()
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl $crate::HasSome for $ty {
+            fn has_some(&self) -> bool {
+                !self.is_empty()
+            }
+            fn is_empty2(self: &&Self) -> bool {
+                (*self).is_empty()
+            }
+        }
+    };
+}
+
 //Used for proc macs
 //impl HasSome for TokenStream { fn has_some(&self) -> bool { !self.is_empty() } }
 